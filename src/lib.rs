@@ -29,26 +29,35 @@
 #[macro_use]
 extern crate lazy_static;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use regex::Regex;
 
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "serde")]
+pub use serde::SerdeError;
+
 #[derive(Clone, Debug)]
-enum Line {
+enum Line<'a> {
     Blank,
-    Comment(String),
-    Entry(Entry),
+    Comment(Cow<'a, str>),
+    Entry(Entry<'a>),
 }
 
 #[derive(Clone, Debug)]
-struct Entry {
-    key: String,
-    value: String,
+struct Entry<'a> {
+    key: Cow<'a, str>,
+    value: Cow<'a, str>,
 }
 
-impl Entry {
-    pub fn new(key: String, value: String) -> Self {
-        Self { key, value }
+impl<'a> Entry<'a> {
+    pub fn new(key: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
     }
 
     pub fn get_value(&self) -> &str {
@@ -59,26 +68,79 @@ impl Entry {
         &self.key
     }
 
-    pub fn set_value(&mut self, value: String) {
-        self.value = value;
+    pub fn set_value(&mut self, value: impl Into<Cow<'a, str>>) {
+        self.value = value.into();
     }
 }
 
 /// The main struct of this crate. Represents DF config file, while also providing functions to parse and manipulate the data.
 /// See crate doc for example usage.
+///
+/// The lifetime `'a` is that of the input buffer entries may borrow from; the owning form
+/// produced by [`Config::read_str`] is `Config<'static>`. Use
+/// [`Config::read_borrowed`] for near-allocation-free, read-only parsing that borrows from the
+/// input directly, allocating only on [`set`](Config::set) and other mutations.
 #[doc(inline)]
 #[derive(Clone, Debug)]
-pub struct Config {
-    lines: Vec<Line>,
+pub struct Config<'a> {
+    lines: Vec<Line<'a>>,
+    /// In-memory override tier that wins over parsed file entries when read, without touching
+    /// the underlying [`lines`](Self::lines) so [`print`](Self::print) stays faithful to the file.
+    overrides: HashMap<String, String>,
 }
 
-impl Config {
+impl<'a> Config<'a> {
     /// Creates an empty config.
     pub fn new() -> Self {
-        Self { lines: vec![] }
+        Self {
+            lines: vec![],
+            overrides: HashMap::new(),
+        }
     }
 
-    /// Parse the config from a string.
+    /// Parse the config from a borrowed string, borrowing keys, values and comments directly
+    /// from `input` instead of allocating a `String` per line.
+    ///
+    /// Mutations such as [`set`](Self::set) allocate only the entries they touch, leaving the
+    /// rest borrowed. The owning [`read_str`](Self::read_str) is preferred when the input buffer
+    /// does not outlive the config.
+    pub fn read_borrowed(input: &'a str) -> Self {
+        Self::parse_lines(input, Cow::Borrowed)
+    }
+
+    fn parse_lines(input: &'a str, mut borrow: impl FnMut(&'a str) -> Cow<'a, str>) -> Self {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^\[([\w\d]+):([\w\d:]+)\]$").unwrap();
+        }
+        let mut lines = Vec::<Line>::new();
+        for l in input.lines() {
+            let lt = l.trim_end();
+
+            if lt.is_empty() {
+                lines.push(Line::Blank);
+                continue;
+            }
+
+            let captures = RE.captures(lt);
+            match captures {
+                Some(c) => lines.push(Line::Entry(Entry::new(
+                    borrow(c.get(1).unwrap().as_str()),
+                    borrow(c.get(2).unwrap().as_str()),
+                ))),
+                None => lines.push(Line::Comment(borrow(l))),
+            };
+        }
+
+        Self {
+            lines,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl Config<'static> {
+    /// Parse the config from a string, taking ownership of the parsed key, value and comment
+    /// strings. See [`read_borrowed`](Config::read_borrowed) for an allocation-light alternative.
     pub fn read_str<T: AsRef<str>>(input: T) -> Self {
         lazy_static! {
             static ref RE: Regex = Regex::new(r"^\[([\w\d]+):([\w\d:]+)\]$").unwrap();
@@ -98,16 +160,78 @@ impl Config {
                     c.get(1).unwrap().as_str().to_owned(),
                     c.get(2).unwrap().as_str().to_owned(),
                 ))),
-                None => lines.push(Line::Comment(l.to_owned())),
+                None => lines.push(Line::Comment(Cow::Owned(l.to_owned()))),
+            };
+        }
+
+        Self {
+            lines,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Parses the config strictly, reporting every line that starts with `[` but fails the entry
+    /// syntax instead of silently reclassifying it as a comment.
+    ///
+    /// On success returns the parsed [`Config`]; otherwise returns the list of
+    /// [`ParseWarning`]s, each carrying the 1-based line number and the offending text. Use
+    /// [`read_str`](Self::read_str) for the lenient behavior that never fails.
+    pub fn parse<T: AsRef<str>>(input: T) -> Result<Self, Vec<ParseWarning>> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^\[([\w\d]+):([\w\d:]+)\]$").unwrap();
+        }
+        let mut lines = Vec::<Line>::new();
+        let mut warnings = Vec::<ParseWarning>::new();
+        for (i, l) in input.as_ref().lines().enumerate() {
+            let lt = l.trim_end();
+
+            if lt.is_empty() {
+                lines.push(Line::Blank);
+                continue;
+            }
+
+            match RE.captures(lt) {
+                Some(c) => lines.push(Line::Entry(Entry::new(
+                    c.get(1).unwrap().as_str().to_owned(),
+                    c.get(2).unwrap().as_str().to_owned(),
+                ))),
+                None => {
+                    if lt.starts_with('[') {
+                        warnings.push(ParseWarning {
+                            line: i + 1,
+                            text: l.to_owned(),
+                        });
+                    }
+                    lines.push(Line::Comment(Cow::Owned(l.to_owned())));
+                }
             };
         }
 
-        Self { lines }
+        if warnings.is_empty() {
+            Ok(Self {
+                lines,
+                overrides: HashMap::new(),
+            })
+        } else {
+            Err(warnings)
+        }
+    }
+
+    /// Alias for [`parse`](Self::parse), mirroring the [`read_str`](Self::read_str) naming.
+    pub fn read_str_strict<T: AsRef<str>>(input: T) -> Result<Self, Vec<ParseWarning>> {
+        Self::parse(input)
     }
+}
 
+impl<'a> Config<'a> {
     /// Tries to retrieve the value for `key`.
-    /// If the key is defined more than once, returns the value of the last occurrence.
+    ///
+    /// An [override](Self::set_override) for `key` always wins. Otherwise, if the key is
+    /// defined more than once in the file, the value of the last occurrence is returned.
     pub fn get<T: AsRef<str>>(&self, key: T) -> Option<&str> {
+        if let Some(value) = self.overrides.get(key.as_ref()) {
+            return Some(value.as_str());
+        }
         self.lines.iter().rev().find_map(|x| match x {
             Line::Entry(entry) => {
                 if entry.get_key() == key.as_ref() {
@@ -120,21 +244,158 @@ impl Config {
         })
     }
 
-    /// Sets all the occurrences of `key` to `value`
+    /// Retrieves the value for `key` split on the `:` separator.
+    ///
+    /// DF packs several sub-values into a single entry (e.g. `[EMBARK_RECTANGLE:2:2]`);
+    /// this returns each field as a separate slice while [`Config::get`] still returns the
+    /// raw joined form. Returns `None` if the key is not present.
+    pub fn get_values<T: AsRef<str>>(&self, key: T) -> Option<Vec<&str>> {
+        self.get(key).map(|v| v.split(':').collect())
+    }
+
+    /// Sets all the occurrences of `key` to the `:`-joined form of `values`.
     ///
     /// # Panics
     ///
-    /// Panics if `key` or `value` is either empty or non-alphanumeric.
-    pub fn set<T: AsRef<str>, U: Into<String>>(&mut self, key: T, value: U) {
+    /// Panics if `key` is either empty or non-alphanumeric, or if `values` is empty or any
+    /// value is either empty or non-alphanumeric. Underscores count as alphanumeric here.
+    pub fn set_values<T: AsRef<str>, U: AsRef<str>>(&mut self, key: T, values: &[U]) {
         let key = key.as_ref();
-        let value = value.into();
         if key.is_empty()
-            || !key.chars().all(|x| x.is_alphanumeric())
-            || value.is_empty()
-            || !value.chars().all(|x| x.is_alphanumeric())
+            || !key.chars().all(is_token_char)
+            || values.is_empty()
+            || values
+                .iter()
+                .any(|v| v.as_ref().is_empty() || !v.as_ref().chars().all(is_token_char))
         {
             panic!("Both key and value have to be non-empty alphanumeric strings!")
         }
+        let joined = values
+            .iter()
+            .map(|x| x.as_ref())
+            .collect::<Vec<_>>()
+            .join(":");
+
+        let mut n = 0;
+        for e in self.lines.iter_mut() {
+            if let Line::Entry(entry) = e {
+                if entry.get_key() == key {
+                    entry.set_value(joined.clone());
+                    n += 1;
+                }
+            }
+        }
+
+        if n == 0 {
+            self.lines
+                .push(Line::Entry(Entry::new(key.to_string(), joined)));
+        }
+    }
+
+    /// Retrieves the sub-value at `idx` of the `:`-separated value for `key`.
+    ///
+    /// Returns `None` if the key is not present or `idx` is out of range.
+    pub fn get_value_at<T: AsRef<str>>(&self, key: T, idx: usize) -> Option<&str> {
+        self.get(key).and_then(|v| v.split(':').nth(idx))
+    }
+
+    /// Sets the sub-value at `idx` of the `:`-separated value for `key`, leaving the other
+    /// fields intact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` or `value` is either empty or non-alphanumeric (underscores are
+    /// accepted), if `key` is not present, or if `idx` is out of range.
+    pub fn set_value_at<T: AsRef<str>, U: AsRef<str>>(&mut self, key: T, idx: usize, value: U) {
+        let mut values = self
+            .get_values(&key)
+            .unwrap_or_else(|| panic!("Key is not present in this config!"))
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        let slot = values
+            .get_mut(idx)
+            .unwrap_or_else(|| panic!("Value index out of range!"));
+        *slot = value.as_ref().to_string();
+        self.set_values(key, &values);
+    }
+
+    /// Sets all the occurrences of `key` to `value`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` or `value` is either empty or non-alphanumeric (underscores are
+    /// accepted). See [`try_set`](Self::try_set) for a non-panicking variant.
+    pub fn set<T: AsRef<str>, U: Into<String>>(&mut self, key: T, value: U) {
+        self.try_set(key, value)
+            .unwrap_or_else(|_| panic!("Both key and value have to be non-empty alphanumeric strings!"))
+    }
+
+    /// Sets all the occurrences of `key` to `value`, returning a [`ConfigError`] instead of
+    /// panicking when `key` or `value` is empty or non-alphanumeric. Underscores are accepted,
+    /// matching the `\w` class the parser uses.
+    ///
+    /// This lets editor tooling report a typo to the user rather than aborting.
+    pub fn try_set<T: AsRef<str>, U: Into<String>>(
+        &mut self,
+        key: T,
+        value: U,
+    ) -> Result<(), ConfigError> {
+        let key = key.as_ref();
+        let value = value.into();
+        if key.is_empty() {
+            return Err(ConfigError::EmptyKey);
+        }
+        if !key.chars().all(is_token_char) {
+            return Err(ConfigError::NonAlphanumeric(key.to_string()));
+        }
+        if value.is_empty() {
+            return Err(ConfigError::EmptyValue);
+        }
+        if !value.chars().all(is_token_char) {
+            return Err(ConfigError::NonAlphanumeric(value));
+        }
+        let mut n = 0;
+        for e in self.lines.iter_mut() {
+            if let Line::Entry(entry) = e {
+                if entry.get_key() == key {
+                    entry.set_value(value.clone());
+                    n += 1;
+                }
+            }
+        }
+
+        if n == 0 {
+            self.lines
+                .push(Line::Entry(Entry::new(key.to_string(), value)));
+        }
+        Ok(())
+    }
+
+    /// Merges `other` into this config, with `other`'s values taking precedence.
+    ///
+    /// For every key present in `other`, the effective (last-occurrence) value is written into
+    /// `self`: existing occurrences are updated in place and keys not yet present are appended
+    /// at the end. Comments and blank lines already in `self` are left untouched, mirroring the
+    /// last-occurrence-wins semantics of [`Config::get`]. This is the building block behind
+    /// [`ConfigBuilder`], letting a shipped-default file be overlaid with a user's overrides.
+    pub fn merge(&mut self, other: &Config<'_>) {
+        let mut seen = Vec::<&str>::new();
+        for key in other.keys_iter() {
+            if seen.contains(&key) {
+                continue;
+            }
+            seen.push(key);
+            // Safe to unwrap: the key came from `other`'s own entries.
+            let value = other.get(key).unwrap().to_owned();
+            self.upsert_raw(key, value);
+        }
+    }
+
+    /// Writes `value` to every occurrence of `key`, appending a new entry if none exist.
+    /// Unlike [`Config::set`] this performs no validation, so it accepts the raw joined value
+    /// form (including `:` separators) coming from another parsed config.
+    fn upsert_raw(&mut self, key: &str, value: String) {
         let mut n = 0;
         for e in self.lines.iter_mut() {
             if let Line::Entry(entry) = e {
@@ -198,15 +459,77 @@ impl Config {
         })
     }
 
-    /// Returns an iterator over (`key`, `value`) tuples.
+    /// Returns an iterator over (`key`, `value`) tuples, with any
+    /// [overrides](Self::set_override) applied to the yielded values.
+    ///
+    /// Overrides for keys that are not present in the file are yielded after the file entries,
+    /// so an override-only key is observed here just as it is through [`get`](Self::get).
     pub fn keys_values_iter(&self) -> impl Iterator<Item = (&str, &str)> + '_ {
-        self.lines.iter().filter_map(|x| {
+        let from_lines = self.lines.iter().filter_map(move |x| {
             if let Line::Entry(entry) = x {
-                Some((entry.get_key(), entry.get_value()))
+                let value = self
+                    .overrides
+                    .get(entry.get_key())
+                    .map(|v| v.as_str())
+                    .unwrap_or_else(|| entry.get_value());
+                Some((entry.get_key(), value))
             } else {
                 None
             }
-        })
+        });
+        let override_only = self.overrides.iter().filter_map(move |(key, value)| {
+            let in_lines = self.lines.iter().any(|x| {
+                matches!(x, Line::Entry(entry) if entry.get_key() == key.as_str())
+            });
+            if in_lines {
+                None
+            } else {
+                Some((key.as_str(), value.as_str()))
+            }
+        });
+        from_lines.chain(override_only)
+    }
+
+    /// Sets an in-memory override for `key` that always wins when read through [`get`](Self::get)
+    /// and [`keys_values_iter`](Self::keys_values_iter), without modifying the parsed file.
+    ///
+    /// This leaves [`print`](Self::print) emitting the original file unchanged; call
+    /// [`flatten_overrides`](Self::flatten_overrides) to fold the overrides back into the file
+    /// contents. Useful for CI or launcher scenarios that tweak settings without rewriting files.
+    pub fn set_override<T: Into<String>, U: Into<String>>(&mut self, key: T, value: U) {
+        self.overrides.insert(key.into(), value.into());
+    }
+
+    /// Scans environment variables named `PREFIX_<KEY>` and sets the matching config key to the
+    /// variable's value.
+    ///
+    /// Keys and values are subject to the same validation as [`set`](Self::set), so a variable
+    /// whose name or value is empty or non-alphanumeric (underscores aside) is skipped rather
+    /// than applied.
+    pub fn apply_env(&mut self, prefix: &str) {
+        let prefix = format!("{}_", prefix);
+        for (name, value) in std::env::vars() {
+            let Some(key) = name.strip_prefix(&prefix) else {
+                continue;
+            };
+            if key.is_empty()
+                || !key.chars().all(is_token_char)
+                || value.is_empty()
+                || !value.chars().all(is_token_char)
+            {
+                continue;
+            }
+            self.set(key, value);
+        }
+    }
+
+    /// Folds all [overrides](Self::set_override) into the file contents and clears the override
+    /// tier, so the changes become visible in [`print`](Self::print).
+    pub fn flatten_overrides(&mut self) {
+        let overrides = std::mem::take(&mut self.overrides);
+        for (key, value) in overrides {
+            self.upsert_raw(&key, value);
+        }
     }
 
     /// Returns the string representing the configuration in its current state (aka what you'd write to the file usually).
@@ -226,8 +549,91 @@ impl Config {
     }
 }
 
-impl From<Config> for HashMap<String, String> {
-    fn from(conf: Config) -> Self {
+/// Returns `true` if `c` may appear in a key or single value, mirroring the `\w` class the
+/// parse regex accepts (alphanumerics plus `_`), so names like `EMBARK_RECTANGLE` round-trip.
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Error returned by [`Config::try_set`] when a key or value fails validation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The key was empty.
+    EmptyKey,
+    /// The value was empty.
+    EmptyValue,
+    /// The offending string contained a non-alphanumeric character.
+    NonAlphanumeric(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::EmptyKey => write!(f, "key must not be empty"),
+            ConfigError::EmptyValue => write!(f, "value must not be empty"),
+            ConfigError::NonAlphanumeric(s) => {
+                write!(f, "`{}` is not alphanumeric", s)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A line that looks like an entry (starts with `[`) but fails the entry syntax, reported by
+/// [`Config::parse`] instead of being silently treated as a comment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// 1-based line number of the offending line.
+    pub line: usize,
+    /// The offending line text, as it appeared in the input.
+    pub text: String,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: malformed token `{}`", self.line, self.text)
+    }
+}
+
+/// Builds a single [`Config`] by layering several sources, where later sources override
+/// earlier ones.
+///
+/// The first source provides the base (its comments and blank lines are preserved); each
+/// subsequent source is [`merged`](Config::merge) on top, so the typical
+/// `default + user-override` composition is `ConfigBuilder::new().add_source(defaults).add_source(user).build()`.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigBuilder {
+    sources: Vec<Config<'static>>,
+}
+
+impl ConfigBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+        }
+    }
+
+    /// Adds a source on top of the previously added ones.
+    pub fn add_source(mut self, source: Config<'static>) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Collapses all sources into a single config, applying them in the order they were added.
+    pub fn build(self) -> Config<'static> {
+        let mut iter = self.sources.into_iter();
+        let mut config = iter.next().unwrap_or_default();
+        for source in iter {
+            config.merge(&source);
+        }
+        config
+    }
+}
+
+impl From<Config<'static>> for HashMap<String, String> {
+    fn from(conf: Config<'static>) -> Self {
         let mut output = HashMap::new();
         conf.keys_values_iter().for_each(|(key, value)| {
             output.insert(key.to_owned(), value.to_owned());
@@ -236,7 +642,7 @@ impl From<Config> for HashMap<String, String> {
     }
 }
 
-impl Default for Config {
+impl Default for Config<'static> {
     fn default() -> Self {
         Self::new()
     }
@@ -278,6 +684,44 @@ mod tests {
         assert_eq!(c.get(key).unwrap(), value);
     }
 
+    #[test]
+    fn test_get_values() {
+        let key = random_alphanumeric();
+        let a = random_alphanumeric();
+        let b = random_alphanumeric();
+        let c = Config::read_str(format!("[{}:{}:{}]", key, a, b));
+        assert_eq!(c.get_values(&key).unwrap(), vec![a.as_str(), b.as_str()]);
+        assert_eq!(c.get_value_at(&key, 0).unwrap(), a);
+        assert_eq!(c.get_value_at(&key, 1).unwrap(), b);
+        assert_eq!(c.get_value_at(&key, 2), None);
+        assert_eq!(c.get_values(random_alphanumeric()), None);
+    }
+
+    #[test]
+    fn test_set_values() {
+        let key = random_alphanumeric();
+        let a = random_alphanumeric();
+        let b = random_alphanumeric();
+        let d = random_alphanumeric();
+        let mut c = Config::new();
+        c.set_values(&key, &[&a, &b]);
+        assert_eq!(c.get(&key).unwrap(), format!("{}:{}", a, b));
+        c.set_value_at(&key, 1, &d);
+        assert_eq!(c.get_values(&key).unwrap(), vec![a.as_str(), d.as_str()]);
+    }
+
+    #[test]
+    fn test_read_borrowed() {
+        let input = String::from("[SOUND:YES]\r\nfoo bar\r\n[VOLUME:128]");
+        let mut conf = Config::read_borrowed(&input);
+        assert_eq!(conf.get("SOUND").unwrap(), "YES");
+        assert_eq!(conf.get("VOLUME").unwrap(), "128");
+        // Mutation still works, allocating only the touched entry.
+        conf.set("VOLUME", "255");
+        assert_eq!(conf.get("VOLUME").unwrap(), "255");
+        assert!(conf.print().contains("foo bar"));
+    }
+
     #[test]
     fn test_basic_set() {
         let key = random_alphanumeric();
@@ -343,6 +787,29 @@ mod tests {
         c.set("\r", "\n");
     }
 
+    #[test]
+    fn test_try_set() {
+        let mut c = Config::new();
+        assert_eq!(c.try_set("", "foo"), Err(ConfigError::EmptyKey));
+        assert_eq!(c.try_set("KEY", ""), Err(ConfigError::EmptyValue));
+        assert_eq!(
+            c.try_set("KEY", "a:b"),
+            Err(ConfigError::NonAlphanumeric("a:b".to_string()))
+        );
+        assert_eq!(c.try_set("KEY", "VAL"), Ok(()));
+        assert_eq!(c.get("KEY").unwrap(), "VAL");
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let ok = Config::parse("[SOUND:YES]\r\nfoo bar");
+        assert!(ok.is_ok());
+        let err = Config::parse("[SOUND:YES]\r\n[BROKEN\r\nfoo bar").unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err[0].line, 2);
+        assert_eq!(err[0].text, "[BROKEN");
+    }
+
     #[test]
     fn test_keys_iter() {
         let a: String = random_alphanumeric();
@@ -376,6 +843,63 @@ mod tests {
         assert_eq!(conf.remove(random_alphanumeric()), 0);
     }
 
+    #[test]
+    fn test_merge() {
+        let mut base = Config::read_str("[SOUND:YES]\r\nfoo bar\r\n[VOLUME:128]");
+        let over = Config::read_str("[VOLUME:255]\r\n[FPS:YES]");
+        base.merge(&over);
+        assert_eq!(base.get("SOUND").unwrap(), "YES");
+        assert_eq!(base.get("VOLUME").unwrap(), "255");
+        assert_eq!(base.get("FPS").unwrap(), "YES");
+        // Base comment is preserved and the new key is appended at the end.
+        assert!(base.print().contains("foo bar"));
+        assert!(base.print().ends_with("[FPS:YES]"));
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let defaults = Config::read_str("[SOUND:YES]\r\n[VOLUME:128]");
+        let user = Config::read_str("[VOLUME:255]");
+        let conf = ConfigBuilder::new()
+            .add_source(defaults)
+            .add_source(user)
+            .build();
+        assert_eq!(conf.get("SOUND").unwrap(), "YES");
+        assert_eq!(conf.get("VOLUME").unwrap(), "255");
+    }
+
+    #[test]
+    fn test_set_override() {
+        let mut conf = Config::read_str("[VOLUME:128]");
+        conf.set_override("VOLUME", "255");
+        // Override wins on read, but the file text is untouched.
+        assert_eq!(conf.get("VOLUME").unwrap(), "255");
+        assert_eq!(conf.print(), "[VOLUME:128]");
+        // Flattening folds it into the file.
+        conf.flatten_overrides();
+        assert_eq!(conf.print(), "[VOLUME:255]");
+    }
+
+    #[test]
+    fn test_apply_env() {
+        let key = random_alphanumeric();
+        let value = random_alphanumeric();
+        std::env::set_var(format!("DFTEST_{}", key), &value);
+        let mut conf = Config::new();
+        conf.apply_env("DFTEST");
+        assert_eq!(conf.get(&key).unwrap(), value);
+    }
+
+    #[test]
+    fn test_apply_env_underscore_key() {
+        // Underscored DF keys such as EMBARK_RECTANGLE must be settable via env, matching the
+        // `\w` class the parser accepts.
+        std::env::set_var("DFUS_POPULATION_CAP", "220");
+        let mut conf = Config::new();
+        conf.apply_env("DFUS");
+        assert_eq!(conf.get("POPULATION_CAP").unwrap(), "220");
+    }
+
     #[test]
     fn test_keys_values_iter() {
         let a: String = random_alphanumeric();
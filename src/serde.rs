@@ -0,0 +1,737 @@
+//! Optional [`serde`] integration, gated behind the `serde` feature.
+//!
+//! This maps DF config entries onto typed structs: a `[SOUND:YES]` / `[VOLUME:255]` pair
+//! deserializes into a struct with `sound: bool` and `volume: u8` fields (renamed to `SOUND`
+//! and `VOLUME` with `#[serde(rename)]`, as keys match field names exactly), parsing the value
+//! string according to the target type (`YES`/`NO` for booleans, integers, and the
+//! `:`-separated multi-value form into `Vec<T>` or tuples). [`Config::from_serialize`] does
+//! the reverse, emitting one `[KEY:VALUE]` entry per field.
+
+use std::fmt::{self, Display};
+
+use serde::de::{
+    self, DeserializeOwned, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::ser::{self, Serialize};
+
+use crate::{Config, Entry, Line};
+
+/// Error returned by the serde (de)serialization of a [`Config`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SerdeError {
+    /// A field expected by the target type had no matching entry in the config.
+    MissingKey(String),
+    /// A value string could not be parsed into the requested type.
+    InvalidValue(String),
+    /// A type or shape that cannot be represented in the DF config format was requested.
+    Unsupported(String),
+    /// A free-form message produced by serde.
+    Message(String),
+}
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerdeError::MissingKey(k) => write!(f, "missing config key `{}`", k),
+            SerdeError::InvalidValue(v) => write!(f, "invalid value `{}`", v),
+            SerdeError::Unsupported(s) => write!(f, "unsupported type: {}", s),
+            SerdeError::Message(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+impl de::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerdeError::Message(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        SerdeError::MissingKey(field.to_string())
+    }
+}
+
+impl ser::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        SerdeError::Message(msg.to_string())
+    }
+}
+
+impl<'c> Config<'c> {
+    /// Deserializes this config into a typed struct `T`.
+    ///
+    /// Keys are matched to struct field names (case-sensitive, as they appear in the file),
+    /// and each value string is parsed into the field's type. Only present keys are read, so
+    /// the usual last-occurrence-wins semantics of [`Config::get`] apply.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, SerdeError> {
+        T::deserialize(ConfigDeserializer { config: self })
+    }
+
+    /// Builds a config from a serializable value, emitting one `[KEY:VALUE]` entry per field.
+    pub fn from_serialize<T: Serialize>(value: &T) -> Result<Config<'static>, SerdeError> {
+        value.serialize(ConfigSerializer {
+            lines: Vec::new(),
+        })
+    }
+}
+
+struct ConfigDeserializer<'a, 'c> {
+    config: &'a Config<'c>,
+}
+
+impl<'de, 'a, 'c> Deserializer<'de> for ConfigDeserializer<'a, 'c> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        // Collapse to last-occurrence-wins, preserving first-seen order.
+        let mut keys: Vec<&str> = Vec::new();
+        for (k, _) in self.config.keys_values_iter() {
+            if !keys.contains(&k) {
+                keys.push(k);
+            }
+        }
+        visitor.visit_map(ConfigMap {
+            config: self.config,
+            keys: keys.into_iter(),
+            value: None,
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        // Let the visitor drive: absent keys yield `None` for `Option`/`#[serde(default)]`
+        // fields, while a genuinely missing required field surfaces as
+        // [`SerdeError::MissingKey`] through [`de::Error::missing_field`].
+        self.deserialize_map(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct enum identifier ignored_any
+    }
+}
+
+struct ConfigMap<'a, 'c, I> {
+    config: &'a Config<'c>,
+    keys: I,
+    value: Option<&'a str>,
+}
+
+impl<'de, 'a, 'c, I: Iterator<Item = &'a str>> MapAccess<'de> for ConfigMap<'a, 'c, I> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        match self.keys.next() {
+            Some(key) => {
+                self.value = self.config.get(key);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| SerdeError::Message("value is missing".to_string()))?;
+        seed.deserialize(ValueDeserializer { value })
+    }
+}
+
+/// Deserializes a single DF value string into a scalar, sequence or tuple.
+struct ValueDeserializer<'a> {
+    value: &'a str,
+}
+
+macro_rules! deserialize_parsed {
+    ($method:ident, $visit:ident, $ty:ty) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            let v: $ty = self
+                .value
+                .parse()
+                .map_err(|_| SerdeError::InvalidValue(self.value.to_string()))?;
+            visitor.$visit(v)
+        }
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for ValueDeserializer<'a> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.value)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            "YES" => visitor.visit_bool(true),
+            "NO" => visitor.visit_bool(false),
+            other => Err(SerdeError::InvalidValue(other.to_string())),
+        }
+    }
+
+    deserialize_parsed!(deserialize_i8, visit_i8, i8);
+    deserialize_parsed!(deserialize_i16, visit_i16, i16);
+    deserialize_parsed!(deserialize_i32, visit_i32, i32);
+    deserialize_parsed!(deserialize_i64, visit_i64, i64);
+    deserialize_parsed!(deserialize_u8, visit_u8, u8);
+    deserialize_parsed!(deserialize_u16, visit_u16, u16);
+    deserialize_parsed!(deserialize_u32, visit_u32, u32);
+    deserialize_parsed!(deserialize_u64, visit_u64, u64);
+    deserialize_parsed!(deserialize_f32, visit_f32, f32);
+    deserialize_parsed!(deserialize_f64, visit_f64, f64);
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.value)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.value)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut chars = self.value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => visitor.visit_char(c),
+            _ => Err(SerdeError::InvalidValue(self.value.to_string())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(ValueSeq {
+            parts: self.value.split(':'),
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct map struct enum identifier ignored_any
+    }
+}
+
+struct ValueSeq<'a> {
+    parts: std::str::Split<'a, char>,
+}
+
+impl<'de, 'a> SeqAccess<'de> for ValueSeq<'a> {
+    type Error = SerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        match self.parts.next() {
+            Some(value) => seed.deserialize(ValueDeserializer { value }).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Serializes a struct into a [`Config`], one `[KEY:VALUE]` entry per field.
+struct ConfigSerializer {
+    lines: Vec<Line<'static>>,
+}
+
+macro_rules! reject_scalar {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, _v: $ty) -> Result<Self::Ok, Self::Error> {
+            Err(unsupported("a top-level scalar"))
+        }
+    };
+}
+
+impl ser::Serializer for ConfigSerializer {
+    type Ok = Config<'static>;
+    type Error = SerdeError;
+
+    type SerializeSeq = ser::Impossible<Config<'static>, SerdeError>;
+    type SerializeTuple = ser::Impossible<Config<'static>, SerdeError>;
+    type SerializeTupleStruct = ser::Impossible<Config<'static>, SerdeError>;
+    type SerializeTupleVariant = ser::Impossible<Config<'static>, SerdeError>;
+    type SerializeMap = ConfigMapSerializer;
+    type SerializeStruct = ConfigStructSerializer;
+    type SerializeStructVariant = ser::Impossible<Config<'static>, SerdeError>;
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(ConfigStructSerializer { lines: self.lines })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ConfigMapSerializer {
+            lines: self.lines,
+            key: None,
+        })
+    }
+
+    reject_scalar!(serialize_bool, bool);
+    reject_scalar!(serialize_i8, i8);
+    reject_scalar!(serialize_i16, i16);
+    reject_scalar!(serialize_i32, i32);
+    reject_scalar!(serialize_i64, i64);
+    reject_scalar!(serialize_i128, i128);
+    reject_scalar!(serialize_u8, u8);
+    reject_scalar!(serialize_u16, u16);
+    reject_scalar!(serialize_u32, u32);
+    reject_scalar!(serialize_u64, u64);
+    reject_scalar!(serialize_u128, u128);
+    reject_scalar!(serialize_f32, f32);
+    reject_scalar!(serialize_f64, f64);
+    reject_scalar!(serialize_char, char);
+
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("a top-level scalar"))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("a top-level option"))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _v: &T) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("a top-level option"))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(unsupported("a top-level sequence"))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(unsupported("a top-level tuple"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(unsupported("tuple struct"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported("enum"))
+    }
+}
+
+fn unsupported(what: &str) -> SerdeError {
+    SerdeError::Unsupported(format!("cannot serialize {} into a config", what))
+}
+
+struct ConfigStructSerializer {
+    lines: Vec<Line<'static>>,
+}
+
+impl ser::SerializeStruct for ConfigStructSerializer {
+    type Ok = Config<'static>;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let value = value.serialize(ScalarSerializer)?;
+        self.lines
+            .push(Line::Entry(Entry::new(key.to_string(), value)));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Config {
+            lines: self.lines,
+            overrides: std::collections::HashMap::new(),
+        })
+    }
+}
+
+struct ConfigMapSerializer {
+    lines: Vec<Line<'static>>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for ConfigMapSerializer {
+    type Ok = Config<'static>;
+    type Error = SerdeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(key.serialize(ScalarSerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| SerdeError::Message("map value serialized before key".to_string()))?;
+        let value = value.serialize(ScalarSerializer)?;
+        self.lines.push(Line::Entry(Entry::new(key, value)));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Config {
+            lines: self.lines,
+            overrides: std::collections::HashMap::new(),
+        })
+    }
+}
+
+/// Serializes a single field value into its DF string form (`YES`/`NO`, integers, or a
+/// `:`-joined sequence).
+struct ScalarSerializer;
+
+macro_rules! serialize_display {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+            Ok(v.to_string())
+        }
+    };
+}
+
+impl ser::Serializer for ScalarSerializer {
+    type Ok = String;
+    type Error = SerdeError;
+
+    type SerializeSeq = SeqScalarSerializer;
+    type SerializeTuple = SeqScalarSerializer;
+    type SerializeTupleStruct = SeqScalarSerializer;
+    type SerializeTupleVariant = ser::Impossible<String, SerdeError>;
+    type SerializeMap = ser::Impossible<String, SerdeError>;
+    type SerializeStruct = ser::Impossible<String, SerdeError>;
+    type SerializeStructVariant = ser::Impossible<String, SerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(if v { "YES" } else { "NO" }.to_string())
+    }
+
+    serialize_display!(serialize_i8, i8);
+    serialize_display!(serialize_i16, i16);
+    serialize_display!(serialize_i32, i32);
+    serialize_display!(serialize_i64, i64);
+    serialize_display!(serialize_u8, u8);
+    serialize_display!(serialize_u16, u16);
+    serialize_display!(serialize_u32, u32);
+    serialize_display!(serialize_u64, u64);
+    serialize_display!(serialize_f32, f32);
+    serialize_display!(serialize_f64, f64);
+    serialize_display!(serialize_char, char);
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, v: &T) -> Result<Self::Ok, Self::Error> {
+        v.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SeqScalarSerializer { parts: Vec::new() })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(SeqScalarSerializer { parts: Vec::new() })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(SeqScalarSerializer { parts: Vec::new() })
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported("enum"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unsupported("a nested map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(unsupported("a nested struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _idx: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported("enum"))
+    }
+}
+
+struct SeqScalarSerializer {
+    parts: Vec<String>,
+}
+
+impl ser::SerializeSeq for SeqScalarSerializer {
+    type Ok = String;
+    type Error = SerdeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.parts.push(value.serialize(ScalarSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.parts.join(":"))
+    }
+}
+
+impl ser::SerializeTuple for SeqScalarSerializer {
+    type Ok = String;
+    type Error = SerdeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqScalarSerializer {
+    type Ok = String;
+    type Error = SerdeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Config;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        #[serde(rename = "SOUND")]
+        sound: bool,
+        #[serde(rename = "VOLUME")]
+        volume: u8,
+        #[serde(rename = "EMBARK_RECTANGLE")]
+        embark_rectangle: Vec<u32>,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let settings = Settings {
+            sound: true,
+            volume: 255,
+            embark_rectangle: vec![2, 2],
+        };
+        let conf = Config::from_serialize(&settings).unwrap();
+        assert_eq!(conf.get("SOUND").unwrap(), "YES");
+        assert_eq!(conf.get("VOLUME").unwrap(), "255");
+        assert_eq!(conf.get("EMBARK_RECTANGLE").unwrap(), "2:2");
+        let back: Settings = conf.deserialize().unwrap();
+        assert_eq!(back, settings);
+    }
+
+    #[test]
+    fn test_deserialize_from_file() {
+        let conf = Config::read_str("[SOUND:NO]\r\n[VOLUME:128]\r\n[EMBARK_RECTANGLE:4:5]");
+        let s: Settings = conf.deserialize().unwrap();
+        assert_eq!(
+            s,
+            Settings {
+                sound: false,
+                volume: 128,
+                embark_rectangle: vec![4, 5],
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_key() {
+        let conf = Config::read_str("[SOUND:YES]");
+        let err = conf.deserialize::<Settings>().unwrap_err();
+        assert_eq!(err, SerdeError::MissingKey("VOLUME".to_string()));
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OptionalSettings {
+        #[serde(rename = "SOUND")]
+        sound: bool,
+        #[serde(rename = "VOLUME")]
+        volume: Option<u8>,
+    }
+
+    #[test]
+    fn test_optional_field_absent() {
+        let conf = Config::read_str("[SOUND:YES]");
+        let s: OptionalSettings = conf.deserialize().unwrap();
+        assert_eq!(
+            s,
+            OptionalSettings {
+                sound: true,
+                volume: None,
+            }
+        );
+    }
+}